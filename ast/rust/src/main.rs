@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::fs;
 use std::time::Instant;
 
@@ -32,6 +33,12 @@ enum TokenType {
     STRING = 18,
     // Identifiers
     IDENTIFIER = 19,
+    // Keywords
+    FOR = 20,
+    // Operators
+    RANGE = 21,
+    // Separators
+    COMMA = 22,
 }
 
 impl From<TokenType> for u8 {
@@ -63,6 +70,9 @@ impl From<u8> for TokenType {
             17 => TokenType::NUMBER,
             18 => TokenType::STRING,
             19 => TokenType::IDENTIFIER,
+            20 => TokenType::FOR,
+            21 => TokenType::RANGE,
+            22 => TokenType::COMMA,
             _ => panic!("Invalid TokenType value: {}", value),
         }
     }
@@ -82,6 +92,9 @@ enum NodeType {
     ASSIGNMENT_STATEMENT = 5,
     CONDITION = 6,
     EXPRESSION = 7,
+    FOR_STATEMENT = 8,
+    CALL_EXPRESSION = 9,
+    LITERAL = 10,
 }
 
 impl From<NodeType> for u8 {
@@ -101,17 +114,58 @@ impl From<u8> for NodeType {
             5 => NodeType::ASSIGNMENT_STATEMENT,
             6 => NodeType::CONDITION,
             7 => NodeType::EXPRESSION,
+            8 => NodeType::FOR_STATEMENT,
+            9 => NodeType::CALL_EXPRESSION,
+            10 => NodeType::LITERAL,
             _ => panic!("Invalid NodeType value: {}", value),
         }
     }
 }
 
+// A half-open range of char indices into the source, plus the 1-based
+// line/column of its start, used both to stamp tokens and to render
+// diagnostics against the original source text.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct Span {
+    start: usize,
+    end: usize,
+    line: usize,
+    column: usize,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct Token {
     r#type: TokenType,
     value: String,
-    line: usize,
-    column: usize,
+    span: Span,
+}
+
+#[derive(Debug, Clone)]
+struct ParseError {
+    message: String,
+    span: Span,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Renders a `ParseError` the way a compiler frontend would: the message,
+// the 1-based line number, the offending source line, and a caret run
+// underlining the error's span.
+fn render_error(src: &str, err: &ParseError) -> String {
+    let line_text = src.lines().nth(err.span.line.saturating_sub(1)).unwrap_or("");
+    let underline_len = (err.span.end - err.span.start).max(1);
+    let indent = " ".repeat(err.span.column.saturating_sub(1));
+    let carets = "^".repeat(underline_len);
+    format!(
+        "{} ({}:{})\n{}\n{}{}",
+        err.message, err.span.line, err.span.column, line_text, indent, carets
+    )
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -131,6 +185,9 @@ enum ASTNodeData {
     AssignmentStatement(AssignmentStatementData),
     Condition(ConditionData),
     Expression(ExpressionData),
+    ForStatement(ForStatementData),
+    CallExpression(CallData),
+    Literal(LiteralData),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -177,15 +234,35 @@ struct ConditionData {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct ExpressionData {
-    #[serde(rename = "leftToken")]
-    left_token: Token,
-    operator: Option<String>,
-    right: Option<Box<ASTNode>>,
+    left: Box<ASTNode>,
+    operator: String,
+    right: Box<ASTNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LiteralData {
+    token: Token,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ForStatementData {
+    identifier: String,
+    start: Box<ASTNode>,
+    end: Box<ASTNode>,
+    block: Box<ASTNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CallData {
+    callee: String,
+    arguments: Vec<ASTNode>,
+    #[serde(rename = "isBuiltin")]
+    is_builtin: bool,
 }
 
 // Config struct removed - no longer using config.json
 
-const KEYWORDS: &[&str] = &["var", "if", "else", "while"];
+const KEYWORDS: &[&str] = &["var", "if", "else", "while", "for"];
 
 fn get_keyword_token_type(keyword: &str) -> TokenType {
     match keyword {
@@ -193,10 +270,30 @@ fn get_keyword_token_type(keyword: &str) -> TokenType {
         "if" => TokenType::IF,
         "else" => TokenType::ELSE,
         "while" => TokenType::WHILE,
+        "for" => TokenType::FOR,
         _ => TokenType::IDENTIFIER,
     }
 }
 
+const BUILTINS: &[&str] = &["length", "print", "min", "max"];
+
+fn is_builtin(name: &str) -> bool {
+    BUILTINS.contains(&name)
+}
+
+// Binding power (precedence) of each binary operator, paired with its
+// canonical textual form for the AST. Higher binds tighter, so
+// MULTIPLY/DIVIDE outrank PLUS/MINUS and `a + b * c` parses as `a + (b * c)`.
+fn binding_power(token_type: TokenType) -> Option<(u8, &'static str)> {
+    match token_type {
+        TokenType::PLUS => Some((1, "+")),
+        TokenType::MINUS => Some((1, "-")),
+        TokenType::MULTIPLY => Some((2, "*")),
+        TokenType::DIVIDE => Some((2, "/")),
+        _ => None,
+    }
+}
+
 fn get_loc_from_index(index: usize, input: &str) -> (usize, usize) {
     let mut line = 1;
     let mut column = 1;
@@ -222,8 +319,22 @@ enum TokenizeState {
     Identifier,
 }
 
-fn tokenize(input: &str) -> Vec<Token> {
+// Sub-states of the `Number` lexer state, recognizing the classic
+// floating-point grammar: digits, an optional `.digits`, and an optional
+// `e`/`E` exponent with an optional sign and required digits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum NumberState {
+    Integer,
+    DotSeen,
+    FractionDigits,
+    ExpSign,
+    ExpSigned,
+    ExpDigits,
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, ParseError> {
     let mut state = TokenizeState::Searching;
+    let mut number_state = NumberState::Integer;
     let mut state_start = 0;
     let mut state_start_line = 1;
     let mut state_start_column = 1;
@@ -245,8 +356,7 @@ fn tokenize(input: &str) -> Vec<Token> {
                         tokens.push(Token {
                             r#type: TokenType::LPAREN,
                             value: ch.to_string(),
-                            line: current_line,
-                            column: current_column,
+                            span: Span { start: i, end: i + 1, line: current_line, column: current_column },
                         });
                         state = TokenizeState::Searching;
                     }
@@ -254,8 +364,7 @@ fn tokenize(input: &str) -> Vec<Token> {
                         tokens.push(Token {
                             r#type: TokenType::RPAREN,
                             value: ch.to_string(),
-                            line: current_line,
-                            column: current_column,
+                            span: Span { start: i, end: i + 1, line: current_line, column: current_column },
                         });
                         state = TokenizeState::Searching;
                     }
@@ -263,8 +372,7 @@ fn tokenize(input: &str) -> Vec<Token> {
                         tokens.push(Token {
                             r#type: TokenType::LBRACE,
                             value: ch.to_string(),
-                            line: current_line,
-                            column: current_column,
+                            span: Span { start: i, end: i + 1, line: current_line, column: current_column },
                         });
                         state = TokenizeState::Searching;
                     }
@@ -272,8 +380,7 @@ fn tokenize(input: &str) -> Vec<Token> {
                         tokens.push(Token {
                             r#type: TokenType::RBRACE,
                             value: ch.to_string(),
-                            line: current_line,
-                            column: current_column,
+                            span: Span { start: i, end: i + 1, line: current_line, column: current_column },
                         });
                         state = TokenizeState::Searching;
                     }
@@ -281,11 +388,28 @@ fn tokenize(input: &str) -> Vec<Token> {
                         tokens.push(Token {
                             r#type: TokenType::SEMICOLON,
                             value: ch.to_string(),
-                            line: current_line,
-                            column: current_column,
+                            span: Span { start: i, end: i + 1, line: current_line, column: current_column },
                         });
                         state = TokenizeState::Searching;
                     }
+                    ',' => {
+                        tokens.push(Token {
+                            r#type: TokenType::COMMA,
+                            value: ch.to_string(),
+                            span: Span { start: i, end: i + 1, line: current_line, column: current_column },
+                        });
+                        state = TokenizeState::Searching;
+                    }
+                    '.' if i + 1 < chars.len() && chars[i + 1] == '.' => {
+                        tokens.push(Token {
+                            r#type: TokenType::RANGE,
+                            value: "..".to_string(),
+                            span: Span { start: i, end: i + 2, line: current_line, column: current_column },
+                        });
+                        current_column += 1;
+                        i += 1;
+                        state = TokenizeState::Searching;
+                    }
                     '+' | '-' | '*' | '/' | '>' | '<' | '=' => {
                         let token_type = match ch {
                             '+' => TokenType::PLUS,
@@ -300,14 +424,16 @@ fn tokenize(input: &str) -> Vec<Token> {
                         tokens.push(Token {
                             r#type: token_type,
                             value: ch.to_string(),
-                            line: current_line,
-                            column: current_column,
+                            span: Span { start: i, end: i + 1, line: current_line, column: current_column },
                         });
                         state = TokenizeState::Searching;
                     }
                     '"' => {
                         if no_dynamic_next {
-                            panic!("Unexpected character: {}", ch);
+                            return Err(ParseError {
+                                message: format!("Unexpected character: {}", ch),
+                                span: Span { start: i, end: i + 1, line: current_line, column: current_column },
+                            });
                         }
                         state_start_line = current_line;
                         state_start_column = current_column;
@@ -315,15 +441,22 @@ fn tokenize(input: &str) -> Vec<Token> {
                     }
                     c if c.is_ascii_digit() => {
                         if no_dynamic_next {
-                            panic!("Unexpected character: {}", ch);
+                            return Err(ParseError {
+                                message: format!("Unexpected character: {}", ch),
+                                span: Span { start: i, end: i + 1, line: current_line, column: current_column },
+                            });
                         }
                         state_start_line = current_line;
                         state_start_column = current_column;
+                        number_state = NumberState::Integer;
                         state = TokenizeState::Number;
                     }
                     c if c.is_ascii_alphabetic() || c == '_' => {
                         if no_dynamic_next {
-                            panic!("Unexpected character: {}", ch);
+                            return Err(ParseError {
+                                message: format!("Unexpected character: {}", ch),
+                                span: Span { start: i, end: i + 1, line: current_line, column: current_column },
+                            });
                         }
                         state_start_line = current_line;
                         state_start_column = current_column;
@@ -332,10 +465,15 @@ fn tokenize(input: &str) -> Vec<Token> {
                     ' ' | '\n' | '\t' => {
                         // Do nothing
                     }
-                    _ => panic!("Unexpected character: {}", ch),
+                    _ => {
+                        return Err(ParseError {
+                            message: format!("Unexpected character: {}", ch),
+                            span: Span { start: i, end: i + 1, line: current_line, column: current_column },
+                        });
+                    }
                 }
                 no_dynamic_next = false;
-                
+
                 // Update position tracking after processing character
                 if ch == '\n' {
                     current_line += 1;
@@ -357,8 +495,7 @@ fn tokenize(input: &str) -> Vec<Token> {
                     tokens.push(Token {
                         r#type: token_type,
                         value: token_value,
-                        line: state_start_line,
-                        column: state_start_column,
+                        span: Span { start: state_start, end: i, line: state_start_line, column: state_start_column },
                     });
                     no_dynamic_next = true;
                     state = TokenizeState::Searching;
@@ -372,8 +509,7 @@ fn tokenize(input: &str) -> Vec<Token> {
                     tokens.push(Token {
                         r#type: TokenType::STRING,
                         value: token_value,
-                        line: state_start_line,
-                        column: state_start_column,
+                        span: Span { start: state_start, end: i, line: state_start_line, column: state_start_column },
                     });
                     no_dynamic_next = true;
                     state = TokenizeState::Searching;
@@ -381,32 +517,108 @@ fn tokenize(input: &str) -> Vec<Token> {
                 i += 1;
             }
             TokenizeState::Number => {
-                if !ch.is_ascii_digit() {
+                let consume = match number_state {
+                    NumberState::Integer => {
+                        if ch.is_ascii_digit() {
+                            true
+                        } else if ch == '.' && !matches!(chars.get(i + 1), Some('.')) {
+                            number_state = NumberState::DotSeen;
+                            true
+                        } else if ch == 'e' || ch == 'E' {
+                            number_state = NumberState::ExpSign;
+                            true
+                        } else {
+                            false
+                        }
+                    }
+                    NumberState::DotSeen => {
+                        if ch.is_ascii_digit() {
+                            number_state = NumberState::FractionDigits;
+                            true
+                        } else {
+                            false
+                        }
+                    }
+                    NumberState::FractionDigits => {
+                        if ch.is_ascii_digit() {
+                            true
+                        } else if ch == 'e' || ch == 'E' {
+                            number_state = NumberState::ExpSign;
+                            true
+                        } else {
+                            false
+                        }
+                    }
+                    NumberState::ExpSign => {
+                        if ch == '+' || ch == '-' {
+                            number_state = NumberState::ExpSigned;
+                            true
+                        } else if ch.is_ascii_digit() {
+                            number_state = NumberState::ExpDigits;
+                            true
+                        } else {
+                            false
+                        }
+                    }
+                    NumberState::ExpSigned => {
+                        if ch.is_ascii_digit() {
+                            number_state = NumberState::ExpDigits;
+                            true
+                        } else {
+                            false
+                        }
+                    }
+                    NumberState::ExpDigits => ch.is_ascii_digit(),
+                };
+
+                if consume {
+                    i += 1;
+                } else {
+                    if matches!(number_state, NumberState::DotSeen | NumberState::ExpSign | NumberState::ExpSigned) {
+                        return Err(ParseError {
+                            message: "malformed number literal".to_string(),
+                            span: Span { start: state_start, end: i, line: state_start_line, column: state_start_column },
+                        });
+                    }
                     let token_value: String = chars[state_start..i].iter().collect();
                     tokens.push(Token {
                         r#type: TokenType::NUMBER,
                         value: token_value,
-                        line: state_start_line,
-                        column: state_start_column,
+                        span: Span { start: state_start, end: i, line: state_start_line, column: state_start_column },
                     });
                     no_dynamic_next = true;
                     state = TokenizeState::Searching;
-                } else {
-                    i += 1;
                 }
             }
         }
     }
 
+    // A number literal truncated by end-of-input (e.g. a trailing `3.` with
+    // no more source after it) never hits the disqualifying-character branch
+    // above, so flush or reject it here the same way that branch would.
+    if matches!(state, TokenizeState::Number) {
+        if matches!(number_state, NumberState::DotSeen | NumberState::ExpSign | NumberState::ExpSigned) {
+            return Err(ParseError {
+                message: "malformed number literal".to_string(),
+                span: Span { start: state_start, end: i, line: state_start_line, column: state_start_column },
+            });
+        }
+        let token_value: String = chars[state_start..i].iter().collect();
+        tokens.push(Token {
+            r#type: TokenType::NUMBER,
+            value: token_value,
+            span: Span { start: state_start, end: i, line: state_start_line, column: state_start_column },
+        });
+    }
+
     // Add EOF token
     tokens.push(Token {
         r#type: TokenType::EOF,
         value: String::new(),
-        line: current_line,
-        column: current_column,
+        span: Span { start: i, end: i, line: current_line, column: current_column },
     });
 
-    tokens
+    Ok(tokens)
 }
 
 struct Parser {
@@ -430,6 +642,12 @@ impl Parser {
         self.current_token().r#type == token_type
     }
 
+    fn peek_at(&self, offset: usize, token_type: TokenType) -> bool {
+        self.tokens
+            .get(self.current_token_index + offset)
+            .is_some_and(|token| token.r#type == token_type)
+    }
+
     fn accept(&mut self, token_type: TokenType) -> bool {
         if self.peek(token_type) {
             self.current_token_index += 1;
@@ -439,237 +657,373 @@ impl Parser {
         }
     }
 
-    fn expect(&mut self, token_type: TokenType) {
-        if !self.accept(token_type) {
-            panic!(
-                "{}:{}: unexpected symbol {:?}",
-                self.current_token().line,
-                self.current_token().column,
-                self.current_token().r#type
-            );
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError {
+            message: message.into(),
+            span: self.current_token().span,
+        }
+    }
+
+    fn expect(&mut self, token_type: TokenType) -> Result<(), ParseError> {
+        if self.accept(token_type) {
+            Ok(())
+        } else {
+            Err(self.error(format!("unexpected symbol {:?}", self.current_token().r#type)))
+        }
+    }
+
+    fn parse_expression(&mut self) -> Result<ASTNode, ParseError> {
+        self.parse_expression_bp(0)
+    }
+
+    // Precedence-climbing (Pratt) parser: parses an atom, then repeatedly
+    // folds in binary operators whose binding power is >= min_bp, recursing
+    // with bp + 1 on the right-hand side so same-precedence operators stay
+    // left-associative (a - b - c == (a - b) - c).
+    fn parse_expression_bp(&mut self, min_bp: u8) -> Result<ASTNode, ParseError> {
+        let mut left = self.parse_atom()?;
+
+        loop {
+            let token_type = self.current_token().r#type;
+            let (bp, operator) = match binding_power(token_type) {
+                Some(bp_and_op) => bp_and_op,
+                None => break,
+            };
+            if bp < min_bp {
+                break;
+            }
+
+            self.current_token_index += 1;
+            let right = self.parse_expression_bp(bp + 1)?;
+
+            left = ASTNode {
+                r#type: NodeType::EXPRESSION,
+                data: ASTNodeData::Expression(ExpressionData {
+                    left: Box::new(left),
+                    operator: operator.to_string(),
+                    right: Box::new(right),
+                }),
+            };
         }
+
+        Ok(left)
     }
 
-    fn parse_expression(&mut self) -> ASTNode {
-        let left_token = self.current_token().clone();
+    fn parse_atom(&mut self) -> Result<ASTNode, ParseError> {
+        let token = self.current_token().clone();
 
-        if self.accept(TokenType::NUMBER)
+        if self.accept(TokenType::LPAREN) {
+            let inner = self.parse_expression_bp(0)?;
+            self.expect(TokenType::RPAREN)?;
+            Ok(inner)
+        } else if self.peek(TokenType::IDENTIFIER) && self.peek_at(1, TokenType::LPAREN) {
+            self.parse_call_expression()
+        } else if self.accept(TokenType::NUMBER)
             || self.accept(TokenType::STRING)
             || self.accept(TokenType::IDENTIFIER)
         {
-            if self.accept(TokenType::PLUS) {
-                let right_node = self.parse_expression();
-                ASTNode {
-                    r#type: NodeType::EXPRESSION,
-                    data: ASTNodeData::Expression(ExpressionData {
-                        left_token,
-                        operator: Some("+".to_string()),
-                        right: Some(Box::new(right_node)),
-                    }),
-                }
-            } else if self.accept(TokenType::MINUS) {
-                let right_node = self.parse_expression();
-                ASTNode {
-                    r#type: NodeType::EXPRESSION,
-                    data: ASTNodeData::Expression(ExpressionData {
-                        left_token,
-                        operator: Some("-".to_string()),
-                        right: Some(Box::new(right_node)),
-                    }),
-                }
-            } else if self.accept(TokenType::MULTIPLY) {
-                let right_node = self.parse_expression();
-                ASTNode {
-                    r#type: NodeType::EXPRESSION,
-                    data: ASTNodeData::Expression(ExpressionData {
-                        left_token,
-                        operator: Some("*".to_string()),
-                        right: Some(Box::new(right_node)),
-                    }),
-                }
-            } else if self.accept(TokenType::DIVIDE) {
-                let right_node = self.parse_expression();
-                ASTNode {
-                    r#type: NodeType::EXPRESSION,
-                    data: ASTNodeData::Expression(ExpressionData {
-                        left_token,
-                        operator: Some("/".to_string()),
-                        right: Some(Box::new(right_node)),
-                    }),
-                }
-            } else {
-                ASTNode {
-                    r#type: NodeType::EXPRESSION,
-                    data: ASTNodeData::Expression(ExpressionData {
-                        left_token,
-                        operator: None,
-                        right: None,
-                    }),
+            Ok(ASTNode {
+                r#type: NodeType::LITERAL,
+                data: ASTNodeData::Literal(LiteralData { token }),
+            })
+        } else {
+            Err(self.error(format!("expression: unexpected symbol {:?}", self.current_token().r#type)))
+        }
+    }
+
+    fn parse_call_expression(&mut self) -> Result<ASTNode, ParseError> {
+        let callee = self.current_token().value.clone();
+        self.expect(TokenType::IDENTIFIER)?;
+        self.expect(TokenType::LPAREN)?;
+
+        let mut arguments = Vec::new();
+        if !self.peek(TokenType::RPAREN) {
+            loop {
+                arguments.push(self.parse_expression()?);
+                if !self.accept(TokenType::COMMA) {
+                    break;
                 }
             }
-        } else {
-            panic!(
-                "expression ({}:{}): unexpected symbol {:?}",
-                self.current_token().line,
-                self.current_token().column,
-                self.current_token().r#type
-            );
         }
+        self.expect(TokenType::RPAREN)?;
+
+        Ok(ASTNode {
+            r#type: NodeType::CALL_EXPRESSION,
+            data: ASTNodeData::CallExpression(CallData {
+                is_builtin: is_builtin(&callee),
+                callee,
+                arguments,
+            }),
+        })
     }
 
-    fn parse_condition(&mut self) -> ASTNode {
-        let left_node = self.parse_expression();
-        
+    fn parse_condition(&mut self) -> Result<ASTNode, ParseError> {
+        let left_node = self.parse_expression()?;
+
         if self.accept(TokenType::GREATER) {
-            ASTNode {
+            Ok(ASTNode {
                 r#type: NodeType::CONDITION,
                 data: ASTNodeData::Condition(ConditionData {
                     left: Box::new(left_node),
                     operator: ">".to_string(),
-                    right: Box::new(self.parse_expression()),
+                    right: Box::new(self.parse_expression()?),
                 }),
-            }
+            })
         } else if self.accept(TokenType::LESS) {
-            ASTNode {
+            Ok(ASTNode {
                 r#type: NodeType::CONDITION,
                 data: ASTNodeData::Condition(ConditionData {
                     left: Box::new(left_node),
                     operator: "<".to_string(),
-                    right: Box::new(self.parse_expression()),
+                    right: Box::new(self.parse_expression()?),
                 }),
-            }
+            })
         } else if self.accept(TokenType::EQUAL) {
-            ASTNode {
+            Ok(ASTNode {
                 r#type: NodeType::CONDITION,
                 data: ASTNodeData::Condition(ConditionData {
                     left: Box::new(left_node),
                     operator: "=".to_string(),
-                    right: Box::new(self.parse_expression()),
+                    right: Box::new(self.parse_expression()?),
                 }),
-            }
+            })
         } else {
-            panic!(
-                "condition ({}:{}): unexpected symbol {:?}",
-                self.current_token().line,
-                self.current_token().column,
-                self.current_token().r#type
-            );
+            Err(self.error(format!("condition: unexpected symbol {:?}", self.current_token().r#type)))
         }
     }
 
-    fn parse_statement(&mut self) -> ASTNode {
+    fn parse_statement(&mut self) -> Result<ASTNode, ParseError> {
         if self.accept(TokenType::VAR) {
             let identifier = self.current_token().value.clone();
-            self.expect(TokenType::IDENTIFIER);
-            ASTNode {
+            self.expect(TokenType::IDENTIFIER)?;
+            Ok(ASTNode {
                 r#type: NodeType::VARIABLE_STATEMENT,
                 data: ASTNodeData::VariableStatement(VariableStatementData {
                     identifier,
                 }),
-            }
+            })
         } else if self.accept(TokenType::IF) {
-            self.expect(TokenType::LPAREN);
-            let condition_node = self.parse_condition();
-            self.expect(TokenType::RPAREN);
-            self.expect(TokenType::LBRACE);
-            let block_node = self.parse_statement_block();
-            self.expect(TokenType::RBRACE);
+            self.expect(TokenType::LPAREN)?;
+            let condition_node = self.parse_condition()?;
+            self.expect(TokenType::RPAREN)?;
+            self.expect(TokenType::LBRACE)?;
+            let block_node = self.parse_statement_block()?;
+            self.expect(TokenType::RBRACE)?;
 
             let else_block_node = if self.accept(TokenType::ELSE) {
-                self.expect(TokenType::LBRACE);
-                let else_block = self.parse_statement_block();
-                self.expect(TokenType::RBRACE);
+                self.expect(TokenType::LBRACE)?;
+                let else_block = self.parse_statement_block()?;
+                self.expect(TokenType::RBRACE)?;
                 Some(else_block)
             } else {
                 None
             };
 
-            ASTNode {
+            Ok(ASTNode {
                 r#type: NodeType::IF_STATEMENT,
                 data: ASTNodeData::IfStatement(IfStatementData {
                     condition: Box::new(condition_node),
                     block: Box::new(block_node),
                     else_block: else_block_node.map(Box::new),
                 }),
-            }
+            })
         } else if self.accept(TokenType::WHILE) {
-            self.expect(TokenType::LPAREN);
-            let condition_node = self.parse_condition();
-            self.expect(TokenType::RPAREN);
-            self.expect(TokenType::LBRACE);
-            let block_node = self.parse_statement_block();
-            self.expect(TokenType::RBRACE);
-
-            ASTNode {
+            self.expect(TokenType::LPAREN)?;
+            let condition_node = self.parse_condition()?;
+            self.expect(TokenType::RPAREN)?;
+            self.expect(TokenType::LBRACE)?;
+            let block_node = self.parse_statement_block()?;
+            self.expect(TokenType::RBRACE)?;
+
+            Ok(ASTNode {
                 r#type: NodeType::WHILE_STATEMENT,
                 data: ASTNodeData::WhileStatement(WhileStatementData {
                     condition: Box::new(condition_node),
                     block: Box::new(block_node),
                 }),
-            }
+            })
+        } else if self.accept(TokenType::FOR) {
+            let identifier = self.current_token().value.clone();
+            self.expect(TokenType::IDENTIFIER)?;
+            let start_node = self.parse_expression()?;
+            self.expect(TokenType::RANGE)?;
+            let end_node = self.parse_expression()?;
+            self.expect(TokenType::LBRACE)?;
+            let block_node = self.parse_statement_block()?;
+            self.expect(TokenType::RBRACE)?;
+
+            Ok(ASTNode {
+                r#type: NodeType::FOR_STATEMENT,
+                data: ASTNodeData::ForStatement(ForStatementData {
+                    identifier,
+                    start: Box::new(start_node),
+                    end: Box::new(end_node),
+                    block: Box::new(block_node),
+                }),
+            })
         } else if self.peek(TokenType::IDENTIFIER) {
             let identifier = self.current_token().value.clone();
             self.accept(TokenType::IDENTIFIER);
-            self.expect(TokenType::EQUAL);
-            let value_node = self.parse_expression();
+            self.expect(TokenType::EQUAL)?;
+            let value_node = self.parse_expression()?;
 
-            ASTNode {
+            Ok(ASTNode {
                 r#type: NodeType::ASSIGNMENT_STATEMENT,
                 data: ASTNodeData::AssignmentStatement(AssignmentStatementData {
                     identifier,
                     value: Box::new(value_node),
                 }),
-            }
+            })
         } else {
-            panic!(
-                "statement ({}:{}): unexpected symbol {:?}",
-                self.current_token().line,
-                self.current_token().column,
-                self.current_token().r#type
-            );
+            Err(self.error(format!("statement: unexpected symbol {:?}", self.current_token().r#type)))
         }
     }
 
-    fn parse_statement_block(&mut self) -> ASTNode {
+    fn parse_statement_block(&mut self) -> Result<ASTNode, ParseError> {
         let mut statements = Vec::new();
 
         loop {
-            statements.push(self.parse_statement());
+            statements.push(self.parse_statement()?);
             if !self.accept(TokenType::SEMICOLON) {
                 break;
             }
         }
 
-        ASTNode {
+        Ok(ASTNode {
             r#type: NodeType::STATEMENT_BLOCK,
             data: ASTNodeData::StatementBlock(StatementBlockData {
                 statements,
             }),
-        }
+        })
     }
 
-    fn parse_program(&mut self) -> ASTNode {
-        let block = self.parse_statement_block();
+    fn parse_program(&mut self) -> Result<ASTNode, ParseError> {
+        let block = self.parse_statement_block()?;
 
         if self.current_token().r#type != TokenType::EOF {
-            panic!(
-                "program ({}:{}): unexpected symbol {:?}",
-                self.current_token().line,
-                self.current_token().column,
-                self.current_token().r#type
-            );
+            return Err(self.error(format!("program: unexpected symbol {:?}", self.current_token().r#type)));
         }
 
-        ASTNode {
+        Ok(ASTNode {
             r#type: NodeType::PROGRAM,
             data: ASTNodeData::Program(ProgramData {
                 block: Box::new(block),
             }),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DumpMode {
+    Tokens,
+    Ast,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum OutputFormat {
+    Json,
+    Debug,
+}
+
+#[derive(Debug)]
+struct CliArgs {
+    file: Option<String>,
+    mode: Option<DumpMode>,
+    format: OutputFormat,
+}
+
+// Minimal hand-rolled flag parser: a positional file path plus
+// `--tokens`/`--ast` (mutually exclusive) and `--format {json,debug}`.
+// Returns `Err` describing the problem on a malformed invocation.
+fn parse_cli_args(args: &[String]) -> Result<CliArgs, String> {
+    let mut file = None;
+    let mut mode = None;
+    let mut format = OutputFormat::Json;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--tokens" => {
+                if mode.is_some() {
+                    return Err("--tokens and --ast are mutually exclusive".to_string());
+                }
+                mode = Some(DumpMode::Tokens);
+            }
+            "--ast" => {
+                if mode.is_some() {
+                    return Err("--tokens and --ast are mutually exclusive".to_string());
+                }
+                mode = Some(DumpMode::Ast);
+            }
+            "--format" => {
+                i += 1;
+                let value = args.get(i).ok_or("--format requires a value")?;
+                format = match value.as_str() {
+                    "json" => OutputFormat::Json,
+                    "debug" => OutputFormat::Debug,
+                    other => return Err(format!("unknown --format value: {}", other)),
+                };
+            }
+            other if file.is_none() => file = Some(other.to_string()),
+            other => return Err(format!("unexpected argument: {}", other)),
         }
+        i += 1;
     }
+
+    Ok(CliArgs { file, mode, format })
+}
+
+fn dump_tokens(tokens: &[Token], format: OutputFormat) -> Result<String, Box<dyn std::error::Error>> {
+    Ok(match format {
+        OutputFormat::Json => serde_json::to_string_pretty(tokens)?,
+        OutputFormat::Debug => format!("{:#?}", tokens),
+    })
+}
+
+fn dump_ast(ast: &ASTNode, format: OutputFormat) -> Result<String, Box<dyn std::error::Error>> {
+    Ok(match format {
+        OutputFormat::Json => serde_json::to_string_pretty(ast)?,
+        OutputFormat::Debug => format!("{:#?}", ast),
+    })
+}
+
+// Inspects a single file in isolation and prints either its token stream
+// or its parsed AST, instead of running the three-file benchmark loop.
+fn run_cli(file: &str, mode: DumpMode, format: OutputFormat) -> Result<(), Box<dyn std::error::Error>> {
+    let contents = fs::read_to_string(file)?;
+
+    let tokens = tokenize(&contents).unwrap_or_else(|err| {
+        eprintln!("{}", render_error(&contents, &err));
+        std::process::exit(1);
+    });
+
+    match mode {
+        DumpMode::Tokens => println!("{}", dump_tokens(&tokens, format)?),
+        DumpMode::Ast => {
+            let mut parser = Parser::new(tokens);
+            let ast = parser.parse_program().unwrap_or_else(|err| {
+                eprintln!("{}", render_error(&contents, &err));
+                std::process::exit(1);
+            });
+            println!("{}", dump_ast(&ast, format)?);
+        }
+    }
+
+    Ok(())
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let cli = parse_cli_args(&args).map_err(|message| {
+        eprintln!("usage: ast [FILE] [--tokens | --ast] [--format {{json,debug}}]");
+        message
+    })?;
+
+    if let Some(file) = &cli.file {
+        let mode = cli.mode.ok_or("--tokens or --ast is required when a file is given")?;
+        return run_cli(file, mode, cli.format);
+    }
+
     // Create output directory
     let output_dir = "../output/rust";
     fs::create_dir_all(output_dir)?;
@@ -688,9 +1042,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     fn parse_file(file_contents: &str, output_filename: &str, iteration: &mut i32, parse_total: &mut f64, marshal_total: &mut f64) -> Result<(), Box<dyn std::error::Error>> {
         let start = Instant::now();
-        let tokens = tokenize(file_contents);
+        let tokens = tokenize(file_contents).unwrap_or_else(|err| {
+            eprintln!("{}", render_error(file_contents, &err));
+            std::process::exit(1);
+        });
         let mut parser = Parser::new(tokens);
-        let ast = parser.parse_program();
+        let ast = parser.parse_program().unwrap_or_else(|err| {
+            eprintln!("{}", render_error(file_contents, &err));
+            std::process::exit(1);
+        });
         let end_parse = Instant::now();
 
         let ast_json = serde_json::to_string_pretty(&ast)?;
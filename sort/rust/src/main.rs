@@ -1,10 +1,222 @@
-use serde::Deserialize;
+use perf::{PerfReadings, PerfSession};
+use serde::{Deserialize, Serialize};
 use std::fs;
 use std::time::Instant;
 
+// Hardware performance counters (cycles, instructions, cache and branch
+// events) around each measured `sort_fn` call. Gated behind the `perf`
+// cargo feature since `perf_event_open` is Linux-only; builds without the
+// feature get the no-op stub below so the rest of the benchmark still builds
+// and runs.
+#[cfg(feature = "perf")]
+mod perf {
+    use perfcnt::linux::{HardwareEventType, PerfCounterBuilderLinux};
+    use perfcnt::{AbstractPerfCounter, PerfCounter};
+
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct PerfReadings {
+        pub cycles: u64,
+        pub instructions: u64,
+        pub cache_references: u64,
+        pub cache_misses: u64,
+        pub branch_instructions: u64,
+    }
+
+    pub struct PerfSession {
+        cycles: PerfCounter,
+        instructions: PerfCounter,
+        cache_references: PerfCounter,
+        cache_misses: PerfCounter,
+        branch_instructions: PerfCounter,
+    }
+
+    impl PerfSession {
+        pub fn new() -> Self {
+            let open = |event: HardwareEventType| {
+                PerfCounterBuilderLinux::from_hardware_event(event)
+                    .finish()
+                    .expect("failed to open performance counter (are perf_event_open permissions set?)")
+            };
+
+            PerfSession {
+                cycles: open(HardwareEventType::CPUCycles),
+                instructions: open(HardwareEventType::Instructions),
+                cache_references: open(HardwareEventType::CacheReferences),
+                cache_misses: open(HardwareEventType::CacheMisses),
+                branch_instructions: open(HardwareEventType::BranchInstructions),
+            }
+        }
+
+        pub fn measure<F: FnOnce()>(&mut self, f: F) -> PerfReadings {
+            for counter in self.counters_mut() {
+                counter.reset().expect("failed to reset performance counter");
+                counter.start().expect("failed to start performance counter");
+            }
+
+            f();
+
+            for counter in self.counters_mut() {
+                counter.stop().expect("failed to stop performance counter");
+            }
+
+            PerfReadings {
+                cycles: self.cycles.read().expect("failed to read cycles counter"),
+                instructions: self.instructions.read().expect("failed to read instructions counter"),
+                cache_references: self.cache_references.read().expect("failed to read cache-references counter"),
+                cache_misses: self.cache_misses.read().expect("failed to read cache-misses counter"),
+                branch_instructions: self.branch_instructions.read().expect("failed to read branch-instructions counter"),
+            }
+        }
+
+        fn counters_mut(&mut self) -> [&mut PerfCounter; 5] {
+            [
+                &mut self.cycles,
+                &mut self.instructions,
+                &mut self.cache_references,
+                &mut self.cache_misses,
+                &mut self.branch_instructions,
+            ]
+        }
+    }
+}
+
+#[cfg(not(feature = "perf"))]
+mod perf {
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct PerfReadings {
+        pub cycles: u64,
+        pub instructions: u64,
+        pub cache_references: u64,
+        pub cache_misses: u64,
+        pub branch_instructions: u64,
+    }
+
+    pub struct PerfSession;
+
+    impl PerfSession {
+        pub fn new() -> Self {
+            PerfSession
+        }
+
+        pub fn measure<F: FnOnce()>(&mut self, f: F) -> PerfReadings {
+            f();
+            PerfReadings::default()
+        }
+    }
+}
+
 #[derive(Deserialize)]
 struct Config {
     iterations: usize,
+    #[serde(default)]
+    warmup: usize,
+    #[serde(default = "default_sizes")]
+    sizes: Vec<usize>,
+    #[serde(default = "default_distributions")]
+    distributions: Vec<String>,
+    #[serde(default = "default_seed")]
+    seed: u64,
+}
+
+fn default_sizes() -> Vec<usize> {
+    vec![1000]
+}
+
+fn default_distributions() -> Vec<String> {
+    vec![
+        "uniform".to_string(),
+        "ascending".to_string(),
+        "descending".to_string(),
+        "mostly-ascending".to_string(),
+        "few-unique".to_string(),
+    ]
+}
+
+fn default_seed() -> u64 {
+    88172645463325252
+}
+
+// A small xorshift64 PRNG. Not cryptographically sound, but deterministic
+// given a seed, so benchmark runs are reproducible without pulling in a
+// dependency just to generate input data.
+struct Xorshift {
+    state: u64,
+}
+
+impl Xorshift {
+    fn new(seed: u64) -> Self {
+        Xorshift {
+            state: if seed == 0 { 1 } else { seed },
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    fn next_index(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    fn next_i16(&mut self) -> i16 {
+        (self.next_u64() % 65536) as i16
+    }
+}
+
+// Maps a position in 0..65536 onto the full i16 range so ascending/descending
+// distributions cycle through every representable value in order.
+fn value_at(index: usize) -> i16 {
+    ((index % 65536) as i32 - 32768) as i16
+}
+
+fn generate_uniform(rng: &mut Xorshift, size: usize) -> Vec<i16> {
+    (0..size).map(|_| rng.next_i16()).collect()
+}
+
+fn generate_ascending(size: usize) -> Vec<i16> {
+    (0..size).map(value_at).collect()
+}
+
+fn generate_descending(size: usize) -> Vec<i16> {
+    (0..size).map(|i| value_at(size - 1 - i)).collect()
+}
+
+// Sorted data disturbed by sqrt(len) random swaps, the "almost sorted"
+// shape that exposes bubble sort's near-best-case behavior.
+fn generate_mostly_ascending(rng: &mut Xorshift, size: usize) -> Vec<i16> {
+    let mut data = generate_ascending(size);
+    if size < 2 {
+        return data;
+    }
+
+    let swaps = (size as f64).sqrt().round() as usize;
+    for _ in 0..swaps {
+        let a = rng.next_index(size);
+        let b = rng.next_index(size);
+        data.swap(a, b);
+    }
+    data
+}
+
+fn generate_few_unique(rng: &mut Xorshift, size: usize) -> Vec<i16> {
+    const UNIQUE_VALUES: usize = 8;
+    (0..size).map(|_| rng.next_index(UNIQUE_VALUES) as i16).collect()
+}
+
+fn generate_distribution(name: &str, size: usize, rng: &mut Xorshift) -> Vec<i16> {
+    match name {
+        "uniform" => generate_uniform(rng, size),
+        "ascending" => generate_ascending(size),
+        "descending" => generate_descending(size),
+        "mostly-ascending" => generate_mostly_ascending(rng, size),
+        "few-unique" => generate_few_unique(rng, size),
+        other => panic!("Unknown distribution: {}", other),
+    }
 }
 
 fn copy_vec(src: &[i16]) -> Vec<i16> {
@@ -30,32 +242,213 @@ fn check_results(data: &[i16], expected: &[i16]) {
     }
 }
 
-fn run_benchmark<F>(name: &str, data: &[i16], expected: &[i16], iterations: usize, mut sort_fn: F)
+#[derive(Serialize, Deserialize, Clone)]
+struct BenchmarkResult {
+    algorithm: String,
+    case: String,
+    size: usize,
+    min_ms: f64,
+    mean_ms: f64,
+    median_ms: f64,
+    max_ms: f64,
+    stddev_ms: f64,
+    throughput_per_sec: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    perf: Option<PerfSummary>,
+}
+
+impl BenchmarkResult {
+    fn display_name(&self) -> String {
+        format!("{} [{}, n={}]", self.algorithm, self.case, self.size)
+    }
+}
+
+// Instructions-per-cycle and cache-miss rate aggregated across every
+// measured iteration, so bubble sort's O(n^2) instruction count shows up
+// directly instead of being hidden behind a median wall-clock time. Branch
+// misses are not counted and are not part of this summary.
+#[derive(Serialize, Deserialize, Clone)]
+struct PerfSummary {
+    instructions_per_cycle: f64,
+    cache_miss_rate: f64,
+}
+
+// Accumulates one result per `run_benchmark` call so the full comparison can
+// be rendered as a single table once every algorithm has run, instead of
+// scattering a median println per algorithm through the benchmark output.
+struct ResultAccumulator {
+    results: Vec<BenchmarkResult>,
+}
+
+impl ResultAccumulator {
+    fn new() -> Self {
+        ResultAccumulator { results: Vec::new() }
+    }
+
+    fn push(&mut self, result: BenchmarkResult) {
+        self.results.push(result);
+    }
+
+    fn print(&self, format: OutputFormat) {
+        match format {
+            OutputFormat::Text => self.print_text(),
+            OutputFormat::Markdown => self.print_markdown(),
+        }
+    }
+
+    fn print_text(&self) {
+        for result in &self.results {
+            println!(
+                "{}: median {:.2}ms (min {:.2}ms, mean {:.2}ms, max {:.2}ms, stddev {:.2}ms, {:.0} elem/s)",
+                result.display_name(),
+                result.median_ms,
+                result.min_ms,
+                result.mean_ms,
+                result.max_ms,
+                result.stddev_ms,
+                result.throughput_per_sec
+            );
+            if let Some(perf) = &result.perf {
+                println!(
+                    "  {:.2} instructions/cycle, {:.2}% cache misses",
+                    perf.instructions_per_cycle,
+                    perf.cache_miss_rate * 100.0
+                );
+            }
+        }
+    }
+
+    fn print_markdown(&self) {
+        let fastest = self
+            .results
+            .iter()
+            .map(|result| result.median_ms)
+            .fold(f64::INFINITY, f64::min);
+        let has_perf = self.results.iter().any(|result| result.perf.is_some());
+
+        if has_perf {
+            println!("| Algorithm | Median (ms) | Mean (ms) | StdDev (ms) | Min (ms) | Max (ms) | Elem/s | IPC | Cache miss % | Speedup |");
+            println!("|---|---|---|---|---|---|---|---|---|---|");
+        } else {
+            println!("| Algorithm | Median (ms) | Mean (ms) | StdDev (ms) | Min (ms) | Max (ms) | Elem/s | Speedup |");
+            println!("|---|---|---|---|---|---|---|---|");
+        }
+
+        for result in &self.results {
+            if has_perf {
+                let (ipc, cache_miss_pct) = result
+                    .perf
+                    .as_ref()
+                    .map(|perf| (perf.instructions_per_cycle, perf.cache_miss_rate * 100.0))
+                    .unwrap_or((0.0, 0.0));
+                println!(
+                    "| {} | {:.2} | {:.2} | {:.2} | {:.2} | {:.2} | {:.0} | {:.2} | {:.2} | {:.2}x |",
+                    result.display_name(),
+                    result.median_ms,
+                    result.mean_ms,
+                    result.stddev_ms,
+                    result.min_ms,
+                    result.max_ms,
+                    result.throughput_per_sec,
+                    ipc,
+                    cache_miss_pct,
+                    fastest / result.median_ms
+                );
+            } else {
+                println!(
+                    "| {} | {:.2} | {:.2} | {:.2} | {:.2} | {:.2} | {:.0} | {:.2}x |",
+                    result.display_name(),
+                    result.median_ms,
+                    result.mean_ms,
+                    result.stddev_ms,
+                    result.min_ms,
+                    result.max_ms,
+                    result.throughput_per_sec,
+                    fastest / result.median_ms
+                );
+            }
+        }
+    }
+}
+
+// Runs `warmup` untimed iterations first to let the branch predictor and
+// caches settle, then times `iterations` measured runs and reports the
+// full spread (min/mean/median/stddev) plus elements-sorted-per-second
+// throughput, which lets algorithms be compared across input sizes.
+fn run_benchmark<F>(
+    algorithm: &str,
+    case: &str,
+    size: usize,
+    data: &[i16],
+    expected: &[i16],
+    warmup: usize,
+    iterations: usize,
+    mut perf_session: Option<&mut PerfSession>,
+    mut sort_fn: F,
+) -> BenchmarkResult
 where
     F: FnMut(&mut [i16]),
 {
+    for _ in 0..warmup {
+        let mut cloned_data = copy_vec(data);
+        sort_fn(&mut cloned_data);
+        check_results(&cloned_data, expected);
+    }
+
     let mut durations = Vec::new();
+    let mut perf_total = PerfReadings::default();
 
-    for i in 0..iterations {
+    for _ in 0..iterations {
         let mut cloned_data = copy_vec(data);
         let start = Instant::now();
-        sort_fn(&mut cloned_data);
+        match perf_session.as_deref_mut() {
+            Some(session) => {
+                let readings = session.measure(|| sort_fn(&mut cloned_data));
+                perf_total.cycles += readings.cycles;
+                perf_total.instructions += readings.instructions;
+                perf_total.cache_references += readings.cache_references;
+                perf_total.cache_misses += readings.cache_misses;
+                perf_total.branch_instructions += readings.branch_instructions;
+            }
+            None => sort_fn(&mut cloned_data),
+        }
         let end = Instant::now();
         let duration = end.duration_since(start);
         check_results(&cloned_data, expected);
         durations.push(duration);
-        println!(
-            "{} iteration {} completed in {:.2}ms",
-            name,
-            i + 1,
-            duration.as_secs_f64() * 1000.0
-        );
     }
 
-    // Calculate median
     durations.sort();
+    let min = durations[0];
+    let max = durations[durations.len() - 1];
     let median = durations[durations.len() / 2];
-    println!("{}: {:.2}ms", name, median.as_secs_f64() * 1000.0);
+
+    let mean_secs =
+        durations.iter().map(|d| d.as_secs_f64()).sum::<f64>() / durations.len() as f64;
+    let variance = durations
+        .iter()
+        .map(|d| (d.as_secs_f64() - mean_secs).powi(2))
+        .sum::<f64>()
+        / durations.len() as f64;
+    let stddev_secs = variance.sqrt();
+
+    let perf = perf_session.map(|_| PerfSummary {
+        instructions_per_cycle: perf_total.instructions as f64 / perf_total.cycles.max(1) as f64,
+        cache_miss_rate: perf_total.cache_misses as f64 / perf_total.cache_references.max(1) as f64,
+    });
+
+    BenchmarkResult {
+        algorithm: algorithm.to_string(),
+        case: case.to_string(),
+        size,
+        min_ms: min.as_secs_f64() * 1000.0,
+        mean_ms: mean_secs * 1000.0,
+        median_ms: median.as_secs_f64() * 1000.0,
+        max_ms: max.as_secs_f64() * 1000.0,
+        stddev_ms: stddev_secs * 1000.0,
+        throughput_per_sec: data.len() as f64 / mean_secs,
+        perf,
+    }
 }
 
 fn bubble_sort(data: &mut [i16]) {
@@ -77,25 +470,33 @@ fn radix_sort(data: &mut [i16]) {
         return;
     }
 
-    // Find maximum value
-    let max = *data.iter().max().unwrap() as i32;
+    // counting_sort's digit extraction assumes non-negative values, so bias
+    // every value into u32 space by subtracting i16::MIN before sorting and
+    // undo the bias once the last pass is done.
+    let mut biased: Vec<u32> = data.iter().map(|&val| (val as i32 - i16::MIN as i32) as u32).collect();
+
+    let max = *biased.iter().max().unwrap();
 
     // Do counting sort for every digit
-    let mut exp = 1i32;
+    let mut exp = 1u32;
     while max / exp > 0 {
-        counting_sort(data, exp);
+        counting_sort(&mut biased, exp);
         exp *= 10;
     }
+
+    for (slot, &val) in data.iter_mut().zip(biased.iter()) {
+        *slot = (val as i32 + i16::MIN as i32) as i16;
+    }
 }
 
-fn counting_sort(data: &mut [i16], exp: i32) {
+fn counting_sort(data: &mut [u32], exp: u32) {
     let n = data.len();
     let mut output = vec![0; n];
     let mut count = vec![0; 10];
 
     // Store count of occurrences
     for &val in data.iter() {
-        count[((val as i32 / exp) % 10) as usize] += 1;
+        count[((val / exp) % 10) as usize] += 1;
     }
 
     // Change count[i] to actual position
@@ -105,7 +506,7 @@ fn counting_sort(data: &mut [i16], exp: i32) {
 
     // Build output array
     for &val in data.iter().rev() {
-        let digit = ((val as i32 / exp) % 10) as usize;
+        let digit = ((val / exp) % 10) as usize;
         count[digit] -= 1;
         output[count[digit]] = val;
     }
@@ -118,41 +519,170 @@ fn builtin_sort(data: &mut [i16]) {
     data.sort();
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+enum OutputFormat {
+    Text,
+    Markdown,
+}
+
+// The registered sorts, keyed by the name `--only` matches against, paired
+// with the display name used in benchmark output.
+const SORTS: &[(&str, &str, fn(&mut [i16]))] = &[
+    ("bubble", "Bubble sort", bubble_sort),
+    ("radix", "Radix sort", radix_sort),
+    ("builtin", "Built-in sort", builtin_sort),
+];
+
+fn selected_sorts(only: &Option<String>) -> Vec<(&'static str, &'static str, fn(&mut [i16]))> {
+    match only {
+        Some(names) => {
+            let wanted: Vec<&str> = names.split(',').map(str::trim).collect();
+            SORTS
+                .iter()
+                .copied()
+                .filter(|(key, _, _)| wanted.contains(key))
+                .collect()
+        }
+        None => SORTS.to_vec(),
+    }
+}
+
+/// Sorting algorithm benchmark harness.
+#[derive(clap::Parser, Debug)]
+struct Cli {
+    /// Override the iteration count from config.json
+    #[arg(long)]
+    iterations: Option<usize>,
+
+    /// Override the warmup count from config.json
+    #[arg(long)]
+    warmup: Option<usize>,
+
+    /// Load input data from a JSON array file instead of generating distributions
+    #[arg(long)]
+    data: Option<String>,
+
+    /// Comma-separated subset of sorts to run, e.g. "bubble,builtin"
+    #[arg(long)]
+    only: Option<String>,
+
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    /// Path to a previously saved results.json to compare this run against
+    #[arg(long)]
+    baseline: Option<String>,
+
+    /// Percentage increase in median time that counts as a regression
+    #[arg(long, default_value_t = 10.0)]
+    regression_threshold: f64,
+}
+
+// Matches a current result against its baseline counterpart by algorithm,
+// case and size (rather than the formatted display name, which would break
+// if the table layout ever changes) and reports any median-time regression
+// beyond `threshold` percent. Returns true if at least one regression was
+// found, so the caller can fail the run.
+fn check_regressions(current: &[BenchmarkResult], baseline: &[BenchmarkResult], threshold: f64) -> bool {
+    let mut regressed = false;
+
+    for result in current {
+        let Some(previous) = baseline.iter().find(|b| {
+            b.algorithm == result.algorithm && b.case == result.case && b.size == result.size
+        }) else {
+            continue;
+        };
+
+        let percent_change = (result.median_ms - previous.median_ms) / previous.median_ms * 100.0;
+        if percent_change > threshold {
+            regressed = true;
+            println!(
+                "REGRESSION: {} median {:.2}ms vs baseline {:.2}ms ({:+.1}%)",
+                result.display_name(),
+                result.median_ms,
+                previous.median_ms,
+                percent_change
+            );
+        }
+    }
+
+    regressed
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Read data.json
-    let data_contents = fs::read_to_string("../data.json")?;
-    let data: Vec<i16> = serde_json::from_str(&data_contents)?;
+    let cli = <Cli as clap::Parser>::parse();
 
     // Read config.json
     let config_contents = fs::read_to_string("../config.json")?;
     let config: Config = serde_json::from_str(&config_contents)?;
 
-    // Create expected sorted data for validation
-    let mut expected = copy_vec(&data);
-    expected.sort();
-
-    // Run benchmarks
-    run_benchmark(
-        "Bubble sort",
-        &data,
-        &expected,
-        config.iterations,
-        bubble_sort,
-    );
-    run_benchmark(
-        "Radix sort",
-        &data,
-        &expected,
-        config.iterations,
-        radix_sort,
-    );
-    run_benchmark(
-        "Built-in sort",
-        &data,
-        &expected,
-        config.iterations,
-        builtin_sort,
-    );
+    let iterations = cli.iterations.unwrap_or(config.iterations);
+    let warmup = cli.warmup.unwrap_or(config.warmup);
+    let sorts = selected_sorts(&cli.only);
+
+    let mut results = ResultAccumulator::new();
+    let mut perf_session = PerfSession::new();
+
+    if let Some(data_path) = &cli.data {
+        let data_contents = fs::read_to_string(data_path)?;
+        let data: Vec<i16> = serde_json::from_str(&data_contents)?;
+        let mut expected = copy_vec(&data);
+        expected.sort();
+
+        for &(_, display_name, sort_fn) in &sorts {
+            results.push(run_benchmark(
+                display_name,
+                data_path,
+                data.len(),
+                &data,
+                &expected,
+                warmup,
+                iterations,
+                if cfg!(feature = "perf") { Some(&mut perf_session) } else { None },
+                sort_fn,
+            ));
+        }
+    } else {
+        let mut rng = Xorshift::new(config.seed);
+
+        for &size in &config.sizes {
+            for distribution in &config.distributions {
+                let data = generate_distribution(distribution, size, &mut rng);
+
+                // Create expected sorted data for validation
+                let mut expected = copy_vec(&data);
+                expected.sort();
+
+                for &(_, display_name, sort_fn) in &sorts {
+                    results.push(run_benchmark(
+                        display_name,
+                        distribution,
+                        size,
+                        &data,
+                        &expected,
+                        warmup,
+                        iterations,
+                        if cfg!(feature = "perf") { Some(&mut perf_session) } else { None },
+                        sort_fn,
+                    ));
+                }
+            }
+        }
+    }
+
+    results.print(cli.format);
+
+    let results_json = serde_json::to_string_pretty(&results.results)?;
+    fs::write("../results.json", results_json)?;
+
+    if let Some(baseline_path) = &cli.baseline {
+        let baseline_contents = fs::read_to_string(baseline_path)?;
+        let baseline: Vec<BenchmarkResult> = serde_json::from_str(&baseline_contents)?;
+
+        if check_regressions(&results.results, &baseline, cli.regression_threshold) {
+            std::process::exit(1);
+        }
+    }
 
     Ok(())
 }